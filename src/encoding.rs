@@ -0,0 +1,188 @@
+//! Percent-encoding helpers for the different URL components.
+
+const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+/// `unreserved` from RFC 3986: `ALPHA / DIGIT / "-" / "." / "_" / "~"`.
+pub(crate) fn is_unreserved(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~')
+}
+
+/// `sub-delims` from RFC 3986.
+pub(crate) fn is_sub_delim(b: u8) -> bool {
+    matches!(
+        b,
+        b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'='
+    )
+}
+
+/// `gen-delims` from RFC 3986.
+pub(crate) fn is_gen_delim(b: u8) -> bool {
+    matches!(b, b':' | b'/' | b'?' | b'#' | b'[' | b']' | b'@')
+}
+
+/// Characters that may appear anywhere in a URL, including `%` for
+/// percent-encoded bytes.
+pub(crate) fn is_url_char(b: u8) -> bool {
+    is_unreserved(b) || is_gen_delim(b) || is_sub_delim(b) || b == b'%'
+}
+
+/// `pchar` from RFC 3986, minus `;` which separates matrix params.
+pub(crate) fn is_segment_char(b: u8) -> bool {
+    is_unreserved(b) || is_sub_delim(b) && b != b';' || matches!(b, b':' | b'@')
+}
+
+/// Characters allowed verbatim in a whole path: `pchar` from RFC 3986,
+/// including `;`, along with the `/` between segments.
+pub(crate) fn is_path_char(b: u8) -> bool {
+    is_segment_char(b) || matches!(b, b';' | b'/')
+}
+
+/// Characters allowed verbatim in a matrix param key or value.
+pub(crate) fn is_matrix_char(b: u8) -> bool {
+    is_segment_char(b) && b != b'='
+}
+
+/// Characters allowed verbatim in a query key or value. This is the RFC 3986
+/// `query` set, minus the characters used as separators by common query
+/// string parsers.
+pub(crate) fn is_query_char(b: u8) -> bool {
+    is_unreserved(b)
+        || is_sub_delim(b) && !matches!(b, b'&' | b'=' | b'+' | b';')
+        || matches!(b, b':' | b'@' | b'/' | b'?')
+}
+
+/// Characters allowed verbatim in a query key or value under the WHATWG URL
+/// standard. Its special-query percent-encode set escapes `'`, but leaves
+/// characters such as `[`, `]` and `|` alone. The separators excluded from
+/// `is_query_char` are still escaped.
+pub(crate) fn is_whatwg_query_char(b: u8) -> bool {
+    is_query_char(b) && b != b'\''
+        || matches!(b, b'[' | b']' | b'|' | b'^' | b'{' | b'}' | b'`' | b'\\')
+}
+
+/// Characters allowed verbatim in the username part of `userinfo`.
+pub(crate) fn is_user_char(b: u8) -> bool {
+    is_unreserved(b) || is_sub_delim(b)
+}
+
+/// Characters allowed verbatim in the password part of `userinfo`.
+pub(crate) fn is_password_char(b: u8) -> bool {
+    is_user_char(b) || b == b':'
+}
+
+/// Characters allowed verbatim in a fragment: the RFC 3986 `fragment` set.
+pub(crate) fn is_fragment_char(b: u8) -> bool {
+    is_unreserved(b) || is_sub_delim(b) || matches!(b, b':' | b'@' | b'/' | b'?')
+}
+
+/// Characters allowed verbatim in a `reg-name` host. Unlike RFC 3986, which
+/// requires them to be percent-encoded, non-ASCII bytes are deliberately
+/// emitted raw so that internationalized hosts stay readable; the crate
+/// doesn't IDN encode them, and `set_strict_host` rejects them instead.
+pub(crate) fn is_reg_name(b: u8) -> bool {
+    is_unreserved(b) || is_sub_delim(b) || !b.is_ascii()
+}
+
+/// Percent-encodes every byte of `input` for which `allowed` returns false.
+///
+/// None of the character sets in this module allow control characters, so
+/// they're always encoded.
+pub(crate) fn percent_encode(input: &str, allowed: fn(u8) -> bool) -> String {
+    let mut encoded = String::with_capacity(input.len());
+
+    for c in input.chars() {
+        let mut buf = [0; 4];
+        let bytes = c.encode_utf8(&mut buf).as_bytes();
+
+        if bytes.iter().all(|&b| allowed(b)) {
+            encoded.push(c);
+            continue;
+        }
+
+        for &b in bytes.iter() {
+            encoded.push('%');
+            encoded.push(HEX[(b >> 4) as usize] as char);
+            encoded.push(HEX[(b & 0xf) as usize] as char);
+        }
+    }
+
+    encoded
+}
+
+/// Returns whether every byte of `input` is either allowed by `allowed` or
+/// part of a well-formed `%XX` sequence.
+pub(crate) fn is_well_encoded(input: &str, allowed: fn(u8) -> bool) -> bool {
+    let bytes = input.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            match bytes.get(i + 1..i + 3) {
+                Some(hex) if hex.iter().all(u8::is_ascii_hexdigit) => i += 3,
+                _ => return false,
+            }
+        } else if allowed(bytes[i]) {
+            i += 1;
+        } else {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Rewrites any `%xx` sequences in `input` to use uppercase hex digits, as
+/// RFC 3986 recommends.
+pub(crate) fn uppercase_escapes(input: &str) -> String {
+    let mut bytes = input.as_bytes().to_vec();
+
+    for i in 0..bytes.len() {
+        let is_escape = bytes[i] == b'%'
+            && bytes
+                .get(i + 1..i + 3)
+                .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit));
+
+        if is_escape {
+            bytes[i + 1].make_ascii_uppercase();
+            bytes[i + 2].make_ascii_uppercase();
+        }
+    }
+
+    String::from_utf8(bytes).expect("only ASCII hex digits were changed")
+}
+
+/// Decodes any `%XX` sequences in `input`. Malformed sequences are left as
+/// they are, and invalid UTF-8 is replaced with `U+FFFD`.
+pub(crate) fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .filter(|hex| bytes[i] == b'%' && hex.iter().all(u8::is_ascii_hexdigit))
+            .map(|hex| (hex_value(hex[0]) << 4) | hex_value(hex[1]));
+
+        match hex {
+            Some(b) => {
+                decoded.push(b);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn hex_value(b: u8) -> u8 {
+    match b {
+        b'0'..=b'9' => b - b'0',
+        b'a'..=b'f' => b - b'a' + 10,
+        _ => b - b'A' + 10,
+    }
+}
@@ -0,0 +1,99 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors that can occur while parsing a URL, or part of one, into a
+/// `URLBuilder`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlParseError {
+    /// The URL has no `scheme:` prefix.
+    MissingScheme,
+    /// The scheme contains characters that aren't allowed in a scheme.
+    InvalidScheme(String),
+    /// The port could not be parsed as a `u16`.
+    InvalidPort(String),
+    /// An IPv6 literal was opened with `[` but never closed.
+    UnclosedBracket(String),
+}
+
+impl fmt::Display for UrlParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UrlParseError::MissingScheme => write!(f, "missing scheme"),
+            UrlParseError::InvalidScheme(scheme) => write!(f, "invalid scheme `{}`", scheme),
+            UrlParseError::InvalidPort(port) => write!(f, "invalid port `{}`", port),
+            UrlParseError::UnclosedBracket(authority) => {
+                write!(f, "unclosed `[` in authority `{}`", authority)
+            }
+        }
+    }
+}
+
+impl Error for UrlParseError {}
+
+/// Errors that can occur while validating a `URLBuilder` before building.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlError {
+    /// The scheme contains characters that aren't allowed in a scheme.
+    InvalidScheme(String),
+    /// The scheme requires a host, but none was set.
+    MissingHost,
+    /// The scheme is opaque (e.g. `mailto`), but no opaque body was set.
+    MissingOpaque,
+    /// The port isn't in the range of a `u16`.
+    InvalidPort,
+    /// The port is below the minimum set with `set_min_port`.
+    PrivilegedPort,
+    /// The host contains non-ASCII characters, which strict host mode
+    /// rejects.
+    NonAsciiHost,
+    /// A component contains a CR or LF, which could be used for header
+    /// injection.
+    ControlCharInComponent,
+    /// The host isn't in the allowlist set with `set_allowed_hosts`.
+    HostNotAllowed,
+    /// The scheme isn't `https`, but a secure url was required.
+    InsecureScheme(String),
+    /// Params are required, but none were set.
+    NoParams,
+    /// More routes were added than the configured maximum allows.
+    TooManyRoutes { max: usize },
+    /// The JSON passed to `set_query_json` isn't an object.
+    QueryJsonNotObject,
+    /// A field of the JSON passed to `set_query_json` is an object or an
+    /// array, which can't be a param value.
+    NestedQueryValue { key: String },
+    /// A `key=value` pair passed to `add_param_pair_str` has no `=`.
+    MalformedPair,
+    /// A param's value is longer than the configured maximum.
+    ParamValueTooLong { key: String },
+}
+
+impl fmt::Display for UrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UrlError::InvalidScheme(scheme) => write!(f, "invalid scheme `{}`", scheme),
+            UrlError::MissingHost => write!(f, "the scheme requires a host"),
+            UrlError::MissingOpaque => write!(f, "the scheme requires an opaque body"),
+            UrlError::InvalidPort => write!(f, "the port is out of range"),
+            UrlError::PrivilegedPort => write!(f, "the port is below the minimum"),
+            UrlError::NonAsciiHost => write!(f, "the host contains non-ASCII characters"),
+            UrlError::ControlCharInComponent => {
+                write!(f, "a component contains a carriage return or line feed")
+            }
+            UrlError::HostNotAllowed => write!(f, "the host isn't allowed"),
+            UrlError::InsecureScheme(scheme) => write!(f, "insecure scheme `{}`", scheme),
+            UrlError::NoParams => write!(f, "at least one param is required"),
+            UrlError::TooManyRoutes { max } => write!(f, "more than {} routes", max),
+            UrlError::QueryJsonNotObject => write!(f, "the query JSON isn't an object"),
+            UrlError::NestedQueryValue { key } => {
+                write!(f, "the query JSON field `{}` is nested", key)
+            }
+            UrlError::MalformedPair => write!(f, "the param pair has no `=`"),
+            UrlError::ParamValueTooLong { key } => {
+                write!(f, "the value of param `{}` is too long", key)
+            }
+        }
+    }
+}
+
+impl Error for UrlError {}
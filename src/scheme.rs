@@ -0,0 +1,59 @@
+use std::fmt;
+
+/// A URL scheme, for setting the protocol without stringly typed values.
+///
+/// # Example
+///
+/// ```
+/// use url_builder::{Scheme, URLBuilder};
+///
+/// let mut ub = URLBuilder::new();
+/// ub.set_scheme(Scheme::Https).set_host("example.com");
+///
+/// assert_eq!("https://example.com", ub.build());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Scheme {
+    Http,
+    Https,
+    Ws,
+    Wss,
+    Ftp,
+    /// Any other scheme, e.g. `git+ssh`.
+    Custom(String),
+}
+
+impl Scheme {
+    /// Returns the scheme as it appears in a URL, without the trailing `:`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Scheme::Http => "http",
+            Scheme::Https => "https",
+            Scheme::Ws => "ws",
+            Scheme::Wss => "wss",
+            Scheme::Ftp => "ftp",
+            Scheme::Custom(scheme) => scheme,
+        }
+    }
+}
+
+impl From<&str> for Scheme {
+    /// Converts a scheme name, ignoring ASCII case, falling back to
+    /// `Scheme::Custom` for unknown schemes.
+    fn from(scheme: &str) -> Self {
+        match scheme.to_ascii_lowercase().as_str() {
+            "http" => Scheme::Http,
+            "https" => Scheme::Https,
+            "ws" => Scheme::Ws,
+            "wss" => Scheme::Wss,
+            "ftp" => Scheme::Ftp,
+            _ => Scheme::Custom(scheme.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Scheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
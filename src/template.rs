@@ -0,0 +1,94 @@
+//! Expansion of RFC 6570 URI templates.
+
+use std::collections::HashMap;
+
+use crate::encoding;
+
+/// Expands the `{var}` expressions in `template` with the matching values
+/// from `vars`, percent-encoding everything but unreserved characters.
+///
+/// Besides level 1's simple `{var}` expressions, the query forms `{?var}` and
+/// `{&var}` are supported, with any number of comma-separated variables.
+/// Undefined variables expand to nothing, and an unclosed `{` is kept as is.
+pub(crate) fn expand(template: &str, vars: &HashMap<&str, &str>) -> String {
+    let mut expanded = String::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        expanded.push_str(&rest[..start]);
+        let expression = &rest[start + 1..];
+
+        match expression.find('}') {
+            Some(end) => {
+                expanded.push_str(&expand_expression(&expression[..end], vars));
+                rest = &expression[end + 1..];
+            }
+            None => {
+                expanded.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    expanded.push_str(rest);
+
+    expanded
+}
+
+/// Expands a single expression, without its braces.
+fn expand_expression(expression: &str, vars: &HashMap<&str, &str>) -> String {
+    let encode = |value: &str| encoding::percent_encode(value, encoding::is_unreserved);
+
+    match expression.strip_prefix(['?', '&']) {
+        Some(names) => {
+            let pairs: Vec<String> = names
+                .split(',')
+                .filter_map(|name| {
+                    vars.get(name)
+                        .map(|value| format!("{}={}", name, encode(value)))
+                })
+                .collect();
+
+            match pairs.is_empty() {
+                true => String::new(),
+                false => format!("{}{}", &expression[..1], pairs.join("&")),
+            }
+        }
+        None => expression
+            .split(',')
+            .filter_map(|name| vars.get(name).map(|value| encode(value)))
+            .collect::<Vec<_>>()
+            .join(","),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_simple() {
+        let vars = HashMap::from([("id", "7"), ("name", "a b")]);
+        assert_eq!("/users/7/a%20b", expand("/users/{id}/{name}", &vars));
+        assert_eq!("/users/", expand("/users/{missing}", &vars));
+    }
+
+    #[test]
+    fn expand_query() {
+        let vars = HashMap::from([("q", "rust"), ("page", "2")]);
+        assert_eq!(
+            "/search?q=rust&page=2",
+            expand("/search{?q,page,sort}", &vars)
+        );
+        assert_eq!(
+            "/search?q=rust&page=2",
+            expand("/search?q={q}{&page}", &vars)
+        );
+        assert_eq!("/search", expand("/search{?sort}", &vars));
+    }
+
+    #[test]
+    fn expand_unclosed_expression() {
+        let vars = HashMap::from([("id", "7")]);
+        assert_eq!("/7/{id", expand("/{id}/{id", &vars));
+    }
+}